@@ -1,3 +1,7 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
 use bevy::{
     math::{ivec3, uvec2},
     prelude::*,
@@ -5,6 +9,7 @@ use bevy::{
 use bevy_simple_tilemap::{plugin::SimpleTileMapPlugin, Tile, TileFlags, TileMap};
 
 use rand::prelude::*;
+use serde::{Deserialize, Serialize};
 
 const TILE_SCALE: f32 = 2.;
 const TILE_WIDTH: f32 = 16. * TILE_SCALE;
@@ -13,6 +18,7 @@ const TILE_ROWS: i32 = 12;
 const TILE_COLUMNS: i32 = 12;
 
 const SNAKE_TIMER_DURATION: f32 = 0.4;
+const INITIAL_SNAKE_LEN: usize = 3;
 
 fn main() {
     App::new()
@@ -28,12 +34,41 @@ fn main() {
                 .set(ImagePlugin::default_nearest()),
         )
         .add_plugins(SimpleTileMapPlugin)
-        .add_systems(Startup, startup)
-        .add_systems(Update, movment)
-        .add_systems(Update, turn)
+        .init_state::<GameState>()
+        .insert_resource(Score {
+            current: 0,
+            best: load_best_score(),
+        })
+        .init_resource::<DifficultyConfig>()
+        .init_resource::<GameRules>()
+        .add_systems(Startup, (spawn_camera, spawn_score_ui))
+        .add_systems(Update, update_score_ui)
+        .add_systems(OnEnter(GameState::Menu), spawn_menu_ui)
+        .add_systems(OnExit(GameState::Menu), (despawn_menu_ui, setup_board))
+        .add_systems(OnEnter(GameState::GameOver), spawn_game_over_ui)
+        .add_systems(
+            OnExit(GameState::GameOver),
+            (despawn_game_over_ui, teardown_board, setup_board).chain(),
+        )
+        .add_systems(
+            Update,
+            (start_game, toggle_wrap_walls, update_menu_ui).run_if(in_state(GameState::Menu)),
+        )
+        .add_systems(Update, (movment, turn).run_if(in_state(GameState::Playing)))
+        .add_systems(Update, toggle_pause)
+        .add_systems(Update, restart.run_if(in_state(GameState::GameOver)))
         .run();
 }
 
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+enum GameState {
+    #[default]
+    Menu,
+    Playing,
+    Paused,
+    GameOver,
+}
+
 enum Direction {
     Up,
     Down,
@@ -71,12 +106,61 @@ struct Food {
     position: IVec3,
 }
 
+#[derive(Component)]
+struct Board;
+
+#[derive(Component)]
+struct MenuUi;
+
+#[derive(Component)]
+struct GameOverUi;
+
+#[derive(Component)]
+struct ScoreUi;
+
+#[derive(Resource, Default)]
+struct Score {
+    current: u32,
+    best: u32,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct HighScoreFile {
+    best: u32,
+}
+
+#[derive(Resource)]
+struct DifficultyConfig {
+    base_duration: f32,
+    growth_factor: f32,
+    min_duration: f32,
+}
+
+impl Default for DifficultyConfig {
+    fn default() -> Self {
+        Self {
+            base_duration: SNAKE_TIMER_DURATION,
+            growth_factor: 0.95,
+            min_duration: 0.1,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct GameRules {
+    wrap_walls: bool,
+}
+
 fn movment(
     mut foods: Query<&mut Food>,
     time: Res<Time>,
     mut timer: ResMut<SnakeTimer>,
     mut snakes: Query<&mut Snake>,
     mut tiles: Query<&mut TileMap>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut score: ResMut<Score>,
+    difficulty: Res<DifficultyConfig>,
+    rules: Res<GameRules>,
 ) {
     if timer.0.tick(time.delta()).finished() {
         let mut food = foods.single_mut();
@@ -89,12 +173,15 @@ fn movment(
             Direction::Left => new_pos.x -= 1,
             Direction::Right => new_pos.x += 1,
         }
-        let is_over = snake.segments.contains(&new_pos)
-            || new_pos.x < 0
-            || new_pos.x >= TILE_COLUMNS
-            || new_pos.y < 0
-            || new_pos.y >= TILE_ROWS;
+        let out_of_bounds =
+            new_pos.x < 0 || new_pos.x >= TILE_COLUMNS || new_pos.y < 0 || new_pos.y >= TILE_ROWS;
+        if rules.wrap_walls && out_of_bounds {
+            new_pos.x = new_pos.x.rem_euclid(TILE_COLUMNS);
+            new_pos.y = new_pos.y.rem_euclid(TILE_ROWS);
+        }
+        let is_over = snake.segments.contains(&new_pos) || (!rules.wrap_walls && out_of_bounds);
         if is_over {
+            next_state.set(GameState::GameOver);
             return;
         }
         if new_pos == food.position {
@@ -109,6 +196,15 @@ fn movment(
                     }),
                 );
             });
+            score.current += 1;
+            if score.current > score.best {
+                score.best = score.current;
+                save_best_score(score.best);
+            }
+            let growth_steps = (snake.segments.len() - INITIAL_SNAKE_LEN) as i32;
+            let duration = (difficulty.base_duration * difficulty.growth_factor.powi(growth_steps))
+                .max(difficulty.min_duration);
+            timer.0.set_duration(Duration::from_secs_f32(duration));
         }
         update_snake(new_pos, &mut snake, &mut tilemap);
     }
@@ -137,6 +233,33 @@ fn turn(input: Res<ButtonInput<KeyCode>>, mut snakes: Query<&mut Snake>) {
     }
 }
 
+fn toggle_pause(
+    input: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !input.just_pressed(KeyCode::Space) {
+        return;
+    }
+    match state.get() {
+        GameState::Playing => next_state.set(GameState::Paused),
+        GameState::Paused => next_state.set(GameState::Playing),
+        _ => {}
+    }
+}
+
+fn start_game(input: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<GameState>>) {
+    if input.just_pressed(KeyCode::Enter) {
+        next_state.set(GameState::Playing);
+    }
+}
+
+fn restart(input: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<GameState>>) {
+    if input.just_pressed(KeyCode::KeyR) {
+        next_state.set(GameState::Playing);
+    }
+}
+
 fn update_snake(new_pos: IVec3, snake: &mut Snake, tilemap: &mut TileMap) {
     for i in 0..snake.segments.len() - 1 {
         let next = snake.segments[i + 1];
@@ -177,12 +300,115 @@ fn update_snake(new_pos: IVec3, snake: &mut Snake, tilemap: &mut TileMap) {
     );
 }
 
-fn startup(
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2d::default());
+}
+
+fn spawn_score_ui(mut commands: Commands) {
+    commands.spawn((
+        Text::new("Score: 0  Best: 0"),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.),
+            left: Val::Px(8.),
+            ..default()
+        },
+        ScoreUi,
+    ));
+}
+
+fn update_score_ui(score: Res<Score>, mut ui: Query<&mut Text, With<ScoreUi>>) {
+    for mut text in ui.iter_mut() {
+        **text = format!("Score: {}  Best: {}", score.current, score.best);
+    }
+}
+
+fn spawn_menu_ui(mut commands: Commands, rules: Res<GameRules>) {
+    commands.spawn((
+        Text::new(menu_text(&rules)),
+        TextFont {
+            font_size: 40.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(40.),
+            left: Val::Percent(30.),
+            ..default()
+        },
+        MenuUi,
+    ));
+}
+
+fn despawn_menu_ui(mut commands: Commands, ui: Query<Entity, With<MenuUi>>) {
+    for entity in ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn menu_text(rules: &GameRules) -> String {
+    format!(
+        "Snake\n\nPress Enter to start\nPress W to toggle wrap walls: {}",
+        if rules.wrap_walls { "ON" } else { "OFF" }
+    )
+}
+
+fn update_menu_ui(rules: Res<GameRules>, mut ui: Query<&mut Text, With<MenuUi>>) {
+    for mut text in ui.iter_mut() {
+        **text = menu_text(&rules);
+    }
+}
+
+fn toggle_wrap_walls(input: Res<ButtonInput<KeyCode>>, mut rules: ResMut<GameRules>) {
+    if input.just_pressed(KeyCode::KeyW) {
+        rules.wrap_walls = !rules.wrap_walls;
+    }
+}
+
+fn spawn_game_over_ui(mut commands: Commands) {
+    commands.spawn((
+        Text::new("Game Over\n\nPress R to restart"),
+        TextFont {
+            font_size: 40.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(40.),
+            left: Val::Percent(30.),
+            ..default()
+        },
+        GameOverUi,
+    ));
+}
+
+fn despawn_game_over_ui(mut commands: Commands, ui: Query<Entity, With<GameOverUi>>) {
+    for entity in ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn teardown_board(mut commands: Commands, board: Query<Entity, With<Board>>) {
+    for entity in board.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn setup_board(
     asset_server: Res<AssetServer>,
     mut commands: Commands,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    mut score: ResMut<Score>,
+    difficulty: Res<DifficultyConfig>,
 ) {
-    commands.spawn(Camera2d::default());
+    score.current = 0;
 
     let image = asset_server.load("textures/tilesheet.png");
     let atlas = TextureAtlasLayout::from_grid(uvec2(16, 16), 5, 1, None, None);
@@ -248,6 +474,7 @@ fn startup(
                 ),
                 ..default()
             },
+            Board,
         ))
         .insert(Snake {
             segments,
@@ -256,11 +483,35 @@ fn startup(
         .insert(Food { position: food });
 
     commands.insert_resource(SnakeTimer(Timer::from_seconds(
-        SNAKE_TIMER_DURATION,
+        difficulty.base_duration,
         TimerMode::Repeating,
     )));
 }
 
+fn high_score_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("snake").join("highscore.json"))
+}
+
+fn load_best_score() -> u32 {
+    high_score_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str::<HighScoreFile>(&contents).ok())
+        .map(|file| file.best)
+        .unwrap_or(0)
+}
+
+fn save_best_score(best: u32) {
+    let Some(path) = high_score_path() else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(contents) = serde_json::to_string(&HighScoreFile { best }) {
+        let _ = fs::write(path, contents);
+    }
+}
+
 fn generate_food(segments: &[IVec3]) -> Option<IVec3> {
     if segments.len() == TILE_ROWS as usize * TILE_COLUMNS as usize {
         return None;